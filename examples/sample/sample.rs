@@ -3,9 +3,10 @@ use safe_linked_list_rust::LinkedList;
 fn main() {
     //Create a new LinkedList of i32 values.
     let mut list = LinkedList::<i32>::new();
-    //Add multiple values to the list:
+    //Add multiple values to the list, keeping a handle to each node:
+    let mut nodes = Vec::new();
     for i in 1..10 {
-        list.add(i);
+        nodes.push(list.add(i));
     }
     //Print the head (1) by chaining next and prev together after retrieving the head element.
     println!("Chaining..");
@@ -19,7 +20,10 @@ fn main() {
         }else if list.is_tail(&i) {
             println!("Tail:{}",i.value); //Display the tail value.
         }
-        i.mutate(4 * i.value); //multiply the value by 4 and set it using mutate.
+    }
+    //multiply each value by 4 through a guard on its node.
+    for node in &nodes {
+        *node.borrow_mut().unwrap() *= 4;
     }
     //iterate backward through the list.
     println!("Backward..");
@@ -0,0 +1,46 @@
+/* C declarations for the `ffi` feature of safe_linked_list_rust.
+ *
+ * Build the shared library with:
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * Every handle returned by a `*_new` function must be released with the
+ * matching `*_free` function and must not be used afterwards. Null handles and
+ * null callbacks are accepted everywhere.
+ */
+#ifndef SAFE_LINKED_LIST_H
+#define SAFE_LINKED_LIST_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct I64List I64List;
+typedef struct BytesList BytesList;
+
+typedef void (*I64Callback)(int64_t value, void *user_data);
+typedef void (*BytesCallback)(const uint8_t *data, size_t len, void *user_data);
+
+I64List *sll_i64_new(void);
+void sll_i64_free(I64List *list);
+size_t sll_i64_len(const I64List *list);
+bool sll_i64_push(I64List *list, int64_t value);
+bool sll_i64_pop_front(I64List *list, int64_t *out);
+bool sll_i64_pop_back(I64List *list, int64_t *out);
+void sll_i64_for_each(const I64List *list, I64Callback callback, void *user_data);
+
+BytesList *sll_bytes_new(void);
+void sll_bytes_free(BytesList *list);
+size_t sll_bytes_len(const BytesList *list);
+bool sll_bytes_push(BytesList *list, const uint8_t *data, size_t len);
+bool sll_bytes_pop_front(BytesList *list, BytesCallback callback, void *user_data);
+void sll_bytes_for_each(const BytesList *list, BytesCallback callback, void *user_data);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SAFE_LINKED_LIST_H */
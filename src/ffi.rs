@@ -0,0 +1,171 @@
+//! C bindings over opaque `LinkedList<i64>` and `LinkedList<Vec<u8>>` handles.
+//!
+//! Every handle returned by a `*_new` function must be released with the
+//! matching `*_free` function and must not be used afterwards. Null handles
+//! are accepted everywhere and treated as an empty list. Null callbacks are
+//! accepted too; the values that would have been passed to them are dropped.
+//!
+//! The crate only builds an rlib. To get a shared library for C, build it
+//! with `cargo rustc --release --features ffi --crate-type cdylib`. The
+//! matching declarations are in `include/safe_linked_list.h`.
+
+use crate::LinkedList;
+use std::{os::raw::c_void, ptr, slice};
+
+pub type I64List = LinkedList<i64>;
+pub type BytesList = LinkedList<Vec<u8>>;
+
+pub type I64Callback = Option<extern "C" fn(value: i64, user_data: *mut c_void)>;
+pub type BytesCallback = Option<extern "C" fn(data: *const u8, len: usize, user_data: *mut c_void)>;
+
+#[no_mangle]
+pub extern "C" fn sll_i64_new() -> *mut I64List {
+    Box::into_raw(Box::new(LinkedList::new()))
+}
+
+/// # Safety
+/// `list` must be null or a handle from `sll_i64_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn sll_i64_free(list: *mut I64List) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_i64_new`.
+#[no_mangle]
+pub unsafe extern "C" fn sll_i64_len(list: *const I64List) -> usize {
+    list.as_ref().map_or(0, LinkedList::len)
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_i64_new`.
+#[no_mangle]
+pub unsafe extern "C" fn sll_i64_push(list: *mut I64List, value: i64) -> bool {
+    match list.as_mut() {
+        Some(list) => list.try_add(value).is_ok(),
+        None => false,
+    }
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_i64_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn sll_i64_pop_front(list: *mut I64List, out: *mut i64) -> bool {
+    pop_i64(list, out, LinkedList::pop_front)
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_i64_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn sll_i64_pop_back(list: *mut I64List, out: *mut i64) -> bool {
+    pop_i64(list, out, LinkedList::pop_back)
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_i64_new`. The list must not
+/// be modified from within `callback`.
+#[no_mangle]
+pub unsafe extern "C" fn sll_i64_for_each(
+    list: *const I64List,
+    callback: I64Callback,
+    user_data: *mut c_void,
+) {
+    if let (Some(list), Some(callback)) = (list.as_ref(), callback) {
+        for node in list.iter() {
+            callback(node.value, user_data);
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn sll_bytes_new() -> *mut BytesList {
+    Box::into_raw(Box::new(LinkedList::new()))
+}
+
+/// # Safety
+/// `list` must be null or a handle from `sll_bytes_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn sll_bytes_free(list: *mut BytesList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_bytes_new`.
+#[no_mangle]
+pub unsafe extern "C" fn sll_bytes_len(list: *const BytesList) -> usize {
+    list.as_ref().map_or(0, LinkedList::len)
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_bytes_new`, and `data` must
+/// be valid for reads of `len` bytes (or null when `len` is zero).
+#[no_mangle]
+pub unsafe extern "C" fn sll_bytes_push(list: *mut BytesList, data: *const u8, len: usize) -> bool {
+    let list = match list.as_mut() {
+        Some(list) => list,
+        None => return false,
+    };
+    let value = if len == 0 {
+        Vec::new()
+    } else if data.is_null() {
+        return false;
+    } else {
+        slice::from_raw_parts(data, len).to_vec()
+    };
+    list.try_add(value).is_ok()
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_bytes_new`. The bytes passed
+/// to `callback` are only valid for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn sll_bytes_pop_front(
+    list: *mut BytesList,
+    callback: BytesCallback,
+    user_data: *mut c_void,
+) -> bool {
+    match list.as_mut().and_then(LinkedList::pop_front) {
+        Some(value) => {
+            if let Some(callback) = callback {
+                callback(value.as_ptr(), value.len(), user_data);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// # Safety
+/// `list` must be null or a live handle from `sll_bytes_new`. The list must not
+/// be modified from within `callback`, and the bytes passed to it are only
+/// valid for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn sll_bytes_for_each(
+    list: *const BytesList,
+    callback: BytesCallback,
+    user_data: *mut c_void,
+) {
+    if let (Some(list), Some(callback)) = (list.as_ref(), callback) {
+        for node in list.iter() {
+            callback(node.value.as_ptr(), node.value.len(), user_data);
+        }
+    }
+}
+
+unsafe fn pop_i64(list: *mut I64List, out: *mut i64, pop: fn(&mut I64List) -> Option<i64>) -> bool {
+    match list.as_mut().and_then(pop) {
+        Some(value) => {
+            if !out.is_null() {
+                ptr::write(out, value);
+            }
+            true
+        }
+        None => false,
+    }
+}
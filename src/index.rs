@@ -0,0 +1,174 @@
+use std::mem;
+
+const NIL: usize = usize::MAX;
+
+struct Entry<L> {
+    value: L,
+    left: usize,
+    right: usize,
+    parent: usize,
+    size: usize,
+    priority: u64,
+}
+
+// An implicit treap keyed by position. Slots are stable for the lifetime of an
+// entry so nodes can find their own position by walking up the parents.
+pub(crate) struct PositionIndex<L> {
+    entries: Vec<Option<Entry<L>>>,
+    free: Vec<usize>,
+    root: usize,
+    seed: u64,
+}
+
+impl<L> PositionIndex<L> {
+    pub(crate) fn new() -> PositionIndex<L> {
+        PositionIndex {
+            entries: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+    pub(crate) fn len(&self) -> usize {
+        self.size(self.root)
+    }
+    pub(crate) fn heap_size(&self) -> usize {
+        self.entries.capacity() * mem::size_of::<Option<Entry<L>>>()
+            + self.free.capacity() * mem::size_of::<usize>()
+    }
+    pub(crate) fn get(&self, position: usize) -> Option<&L> {
+        let mut cur = self.root;
+        let mut position = position;
+        while cur != NIL {
+            let entry = self.entry(cur);
+            let left_size = self.size(entry.left);
+            if position < left_size {
+                cur = entry.left;
+            } else if position == left_size {
+                return Some(&entry.value);
+            } else {
+                position -= left_size + 1;
+                cur = entry.right;
+            }
+        }
+        None
+    }
+    pub(crate) fn value(&self, slot: usize) -> Option<&L> {
+        self.entries.get(slot)?.as_ref().map(|entry| &entry.value)
+    }
+    pub(crate) fn rank(&self, slot: usize) -> Option<usize> {
+        self.value(slot)?;
+        let mut rank = self.size(self.entry(slot).left);
+        let mut cur = slot;
+        while self.entry(cur).parent != NIL {
+            let parent = self.entry(cur).parent;
+            if self.entry(parent).right == cur {
+                rank += self.size(self.entry(parent).left) + 1;
+            }
+            cur = parent;
+        }
+        Some(rank)
+    }
+    pub(crate) fn insert(&mut self, position: usize, value: L) -> usize {
+        let priority = self.next_priority();
+        let entry = Entry {
+            value,
+            left: NIL,
+            right: NIL,
+            parent: NIL,
+            size: 1,
+            priority,
+        };
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.entries[slot] = Some(entry);
+                slot
+            }
+            None => {
+                self.entries.push(Some(entry));
+                self.entries.len() - 1
+            }
+        };
+        let (left, right) = self.split(self.root, position);
+        let left = self.merge(left, slot);
+        self.root = self.merge(left, right);
+        self.entry_mut(self.root).parent = NIL;
+        slot
+    }
+    pub(crate) fn remove(&mut self, slot: usize) -> Option<L> {
+        let position = self.rank(slot)?;
+        let (left, rest) = self.split(self.root, position);
+        let (_, right) = self.split(rest, 1);
+        self.root = self.merge(left, right);
+        if self.root != NIL {
+            self.entry_mut(self.root).parent = NIL;
+        }
+        self.free.push(slot);
+        self.entries[slot].take().map(|entry| entry.value)
+    }
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+    fn entry(&self, slot: usize) -> &Entry<L> {
+        self.entries[slot].as_ref().unwrap()
+    }
+    fn entry_mut(&mut self, slot: usize) -> &mut Entry<L> {
+        self.entries[slot].as_mut().unwrap()
+    }
+    fn size(&self, slot: usize) -> usize {
+        if slot == NIL {
+            0
+        } else {
+            self.entry(slot).size
+        }
+    }
+    fn update(&mut self, slot: usize) {
+        let (left, right) = (self.entry(slot).left, self.entry(slot).right);
+        self.entry_mut(slot).size = 1 + self.size(left) + self.size(right);
+        if left != NIL {
+            self.entry_mut(left).parent = slot;
+        }
+        if right != NIL {
+            self.entry_mut(right).parent = slot;
+        }
+    }
+    fn merge(&mut self, left: usize, right: usize) -> usize {
+        if left == NIL {
+            return right;
+        }
+        if right == NIL {
+            return left;
+        }
+        if self.entry(left).priority > self.entry(right).priority {
+            let merged = self.merge(self.entry(left).right, right);
+            self.entry_mut(left).right = merged;
+            self.update(left);
+            left
+        } else {
+            let merged = self.merge(left, self.entry(right).left);
+            self.entry_mut(right).left = merged;
+            self.update(right);
+            right
+        }
+    }
+    fn split(&mut self, slot: usize, position: usize) -> (usize, usize) {
+        if slot == NIL {
+            return (NIL, NIL);
+        }
+        let left_size = self.size(self.entry(slot).left);
+        if left_size < position {
+            let (left, right) = self.split(self.entry(slot).right, position - left_size - 1);
+            self.entry_mut(slot).right = left;
+            self.update(slot);
+            (slot, right)
+        } else {
+            let (left, right) = self.split(self.entry(slot).left, position);
+            self.entry_mut(slot).left = right;
+            self.update(slot);
+            (left, slot)
+        }
+    }
+}
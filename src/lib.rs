@@ -1,18 +1,103 @@
-use std::{cell::RefCell, rc::Rc};
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod index;
+
+use index::PositionIndex;
+use std::{
+    cell::{BorrowMutError, RefCell, RefMut},
+    collections::HashSet,
+    error::Error,
+    fmt,
+    fmt::Write,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+    rc::Rc,
+    slice,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+static NEXT_LIST_ID: AtomicUsize = AtomicUsize::new(1);
 
 pub struct LinkedList<T: Clone + Default> {
     head: Option<Rc<RefCell<Node<T>>>>,
     tail: Option<Rc<RefCell<Node<T>>>>,
+    length: usize,
+    capacity: Option<usize>,
+    overflow: OverflowPolicy,
+    pool: Vec<Rc<RefCell<Node<T>>>>,
+    recycle_capacity: usize,
+    index: Option<PositionIndex<Rc<RefCell<Node<T>>>>>,
+    allocations: usize,
+    id: usize,
 }
 #[derive(Clone)]
 pub struct Node<T: Clone + Default> {
     pub value: T,
     next_node: Option<Rc<RefCell<Node<T>>>>,
     prev_node: Option<Rc<RefCell<Node<T>>>>,
+    slot: usize,
+    owner: usize,
+}
+#[derive(Clone)]
+pub struct NodeRef<T: Clone + Default> {
+    link: Rc<RefCell<Node<T>>>,
+}
+pub struct NodeGuard<'a, T: Clone + Default> {
+    node: RefMut<'a, Node<T>>,
 }
 pub struct LinkedListIter<T: Clone + Default> {
-    head: Option<Rc<RefCell<Node<T>>>>,
-    cur_node: Option<Rc<RefCell<Node<T>>>>,
+    front: Option<Rc<RefCell<Node<T>>>>,
+    back: Option<Rc<RefCell<Node<T>>>>,
+    remaining: usize,
+    started: bool,
+}
+pub struct LinkedListValues<'a, T: Clone + Default> {
+    front: Option<Rc<RefCell<Node<T>>>>,
+    back: Option<Rc<RefCell<Node<T>>>>,
+    remaining: usize,
+    list: PhantomData<&'a LinkedList<T>>,
+}
+pub struct LinkedListRefs<'a, T: Clone + Default> {
+    front: Option<Rc<RefCell<Node<T>>>>,
+    back: Option<Rc<RefCell<Node<T>>>>,
+    remaining: usize,
+    list: PhantomData<&'a mut LinkedList<T>>,
+}
+pub struct LinkedListIntoIter<T: Clone + Default> {
+    list: LinkedList<T>,
+}
+#[derive(Clone)]
+pub struct FrozenList<T: Clone + Default> {
+    values: Arc<[T]>,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    Reject,
+    EvictHead,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    pub value: T,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Next,
+    Prev,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    HeadTailMismatch,
+    MissingLink { index: usize, direction: Direction },
+    BrokenLink { index: usize, direction: Direction },
+    TailMismatch { index: usize },
+    LengthMismatch { expected: usize, found: usize },
+    RingTooLong { expected: usize },
+    IndexMismatch { index: usize },
 }
 
 impl<T: Clone + Default> Node<T> {
@@ -21,6 +106,8 @@ impl<T: Clone + Default> Node<T> {
             value: T::default(),
             next_node: None,
             prev_node: None,
+            slot: 0,
+            owner: 0,
         }
     }
     pub fn next(&self) -> Node<T> {
@@ -43,6 +130,7 @@ impl<T: Clone + Default> Node<T> {
             panic!("No `prev` available!");
         }
     }
+    #[deprecated(note = "use `NodeRef::borrow_mut` instead")]
     pub fn mutate(&self, value: T) {
         let next_node = self.next_node.clone();
         let this_node = next_node.unwrap().borrow().prev_node.clone().unwrap();
@@ -50,18 +138,423 @@ impl<T: Clone + Default> Node<T> {
     }
 }
 
+impl<T: Clone + Default> NodeRef<T> {
+    pub fn node(&self) -> Node<T> {
+        self.link.borrow().clone()
+    }
+    pub fn value(&self) -> T {
+        self.link.borrow().value.clone()
+    }
+    pub fn set(&self, value: T) {
+        match self.link.try_borrow_mut() {
+            Ok(mut node) => node.value = value,
+            Err(_) => panic!("`Node` is held by a `NodeGuard`!"),
+        }
+    }
+    pub fn borrow_mut(&self) -> Result<NodeGuard<'_, T>, BorrowMutError> {
+        let node = self.link.try_borrow_mut()?;
+        Ok(NodeGuard { node })
+    }
+    pub fn is_linked(&self) -> bool {
+        self.link.borrow().next_node.is_some()
+    }
+    pub fn ptr_eq(&self, other: &NodeRef<T>) -> bool {
+        Rc::ptr_eq(&self.link, &other.link)
+    }
+}
+
+impl<T: Clone + Default> Deref for NodeGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.node.value
+    }
+}
+
+impl<T: Clone + Default> DerefMut for NodeGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.node.value
+    }
+}
+
 impl<T: Clone + Default> LinkedList<T> {
     pub fn new() -> LinkedList<T> {
         LinkedList {
             head: None,
             tail: None,
+            length: 0,
+            capacity: None,
+            overflow: OverflowPolicy::Reject,
+            pool: Vec::new(),
+            recycle_capacity: 0,
+            index: None,
+            allocations: 0,
+            id: NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+    pub fn with_capacity_bounded(capacity: usize, overflow: OverflowPolicy) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        list.capacity = Some(capacity);
+        list.overflow = overflow;
+        list
+    }
+    pub fn add(&mut self, value: T) -> NodeRef<T> {
+        match self.try_add(value) {
+            Ok(node) => node,
+            Err(_) => panic!("`LinkedList` is full!"),
+        }
+    }
+    pub fn try_add(&mut self, value: T) -> Result<NodeRef<T>, CapacityError<T>> {
+        if !self.make_room() {
+            return Err(CapacityError { value });
+        }
+        let link = self.alloc(value);
+        self.link_back(link.clone());
+        Ok(NodeRef { link })
+    }
+    pub fn insert(&mut self, index: usize, value: T) -> NodeRef<T> {
+        if index > self.length {
+            panic!("`index` is out of bounds!");
+        }
+        let length = self.length;
+        if !self.make_room() {
+            panic!("`LinkedList` is full!");
+        }
+        let index = if self.length < length {
+            index.saturating_sub(1)
+        } else {
+            index
+        };
+        let link = self.alloc(value);
+        self.link_before(index, link.clone());
+        NodeRef { link }
+    }
+    pub fn get(&self, index: usize) -> Option<Node<T>> {
+        self.link_at(index).map(|link| link.borrow().clone())
+    }
+    pub fn remove_at(&mut self, index: usize) -> T {
+        let link = match self.link_at(index) {
+            Some(link) => link,
+            None => panic!("`index` is out of bounds!"),
+        };
+        self.unlink(&link);
+        self.release(link)
+    }
+    pub fn enable_index(&mut self) {
+        let mut index = PositionIndex::new();
+        let mut cur_node = self.head.clone();
+        for position in 0..self.length {
+            let link = cur_node.unwrap();
+            link.borrow_mut().slot = index.insert(position, link.clone());
+            cur_node = link.borrow().next_node.clone();
+        }
+        self.index = Some(index);
+    }
+    pub fn disable_index(&mut self) {
+        self.index = None;
+    }
+    pub fn is_indexed(&self) -> bool {
+        self.index.is_some()
+    }
+    pub fn remove(&mut self, node: &NodeRef<T>) -> T {
+        self.unlink(&node.link);
+        self.release(node.link.clone())
+    }
+    pub fn move_to_front(&mut self, node: &NodeRef<T>) {
+        if self
+            .head
+            .as_ref()
+            .is_some_and(|head| Rc::ptr_eq(head, &node.link))
+        {
+            return;
+        }
+        self.unlink(&node.link);
+        self.link_front(node.link.clone());
+    }
+    pub fn move_to_back(&mut self, node: &NodeRef<T>) {
+        if self
+            .tail
+            .as_ref()
+            .is_some_and(|tail| Rc::ptr_eq(tail, &node.link))
+        {
+            return;
+        }
+        self.unlink(&node.link);
+        self.link_back(node.link.clone());
+    }
+    pub fn find_node_by<F>(&self, mut pred: F) -> Option<NodeRef<T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut cur_node = self.head.clone();
+        for _ in 0..self.length {
+            let link = cur_node.unwrap();
+            if pred(&link.borrow().value) {
+                return Some(NodeRef { link });
+            }
+            cur_node = link.borrow().next_node.clone();
+        }
+        None
+    }
+    pub fn min_node(&self) -> Option<NodeRef<T>>
+    where
+        T: Ord,
+    {
+        self.select_node(|value, best| value < best)
+    }
+    pub fn max_node(&self) -> Option<NodeRef<T>>
+    where
+        T: Ord,
+    {
+        self.select_node(|value, best| value >= best)
+    }
+    pub fn head_ref(&self) -> Option<NodeRef<T>> {
+        self.head.clone().map(|link| NodeRef { link })
+    }
+    pub fn tail_ref(&self) -> Option<NodeRef<T>> {
+        self.tail.clone().map(|link| NodeRef { link })
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head.clone()?;
+        self.unlink(&head);
+        Some(self.release(head))
+    }
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail.clone()?;
+        self.unlink(&tail);
+        Some(self.release(tail))
+    }
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let link = match self.link_at(index) {
+            Some(link) => link,
+            None => panic!("`index` is out of bounds!"),
+        };
+        let tail_value = self.pop_back().unwrap();
+        if index == self.length {
+            return tail_value;
+        }
+        let value = mem::replace(&mut link.borrow_mut().value, tail_value);
+        value
+    }
+    pub fn partition<F>(mut self, mut pred: F) -> (LinkedList<T>, LinkedList<T>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut matched = LinkedList::new();
+        let mut rest = LinkedList::new();
+        while let Some(head) = self.head.clone() {
+            self.unlink(&head);
+            let is_match = pred(&head.borrow().value);
+            if is_match {
+                matched.link_back(head);
+            } else {
+                rest.link_back(head);
+            }
+        }
+        (matched, rest)
+    }
+    pub fn zip<U: Clone + Default>(mut self, mut other: LinkedList<U>) -> LinkedList<(T, U)> {
+        let mut zipped = LinkedList::new();
+        while !self.is_empty() && !other.is_empty() {
+            zipped.add((self.pop_front().unwrap(), other.pop_front().unwrap()));
+        }
+        zipped
+    }
+    pub fn zip_longest<U: Clone + Default>(
+        mut self,
+        mut other: LinkedList<U>,
+    ) -> LinkedList<(Option<T>, Option<U>)> {
+        let mut zipped = LinkedList::new();
+        while !self.is_empty() || !other.is_empty() {
+            zipped.add((self.pop_front(), other.pop_front()));
         }
+        zipped
     }
-    pub fn add(&mut self, value: T) {
+    pub fn map<U, F>(&self, mut f: F) -> LinkedList<U>
+    where
+        U: Clone + Default,
+        F: FnMut(&T) -> U,
+    {
+        self.filter_map(|value| Some(f(value)))
+    }
+    pub fn filter_map<U, F>(&self, mut f: F) -> LinkedList<U>
+    where
+        U: Clone + Default,
+        F: FnMut(&T) -> Option<U>,
+    {
+        let mut mapped = LinkedList::new();
+        let mut cur_node = self.head.clone();
+        for _ in 0..self.length {
+            let link = cur_node.unwrap();
+            if let Some(value) = f(&link.borrow().value) {
+                mapped.add(value);
+            }
+            cur_node = link.borrow().next_node.clone();
+        }
+        mapped
+    }
+    pub fn interleave(mut self, mut other: LinkedList<T>) -> LinkedList<T> {
+        let mut interleaved = LinkedList::new();
+        while !self.is_empty() || !other.is_empty() {
+            for list in [&mut self, &mut other] {
+                if let Some(head) = list.head.clone() {
+                    list.unlink(&head);
+                    interleaved.link_back(head);
+                }
+            }
+        }
+        interleaved
+    }
+    pub fn sublist<R: RangeBounds<usize>>(&self, range: R) -> LinkedList<T> {
+        let mut sublist = LinkedList::new();
+        for node in self.iter_range(range) {
+            sublist.add(node.value);
+        }
+        sublist
+    }
+    pub fn split_range<R: RangeBounds<usize>>(&mut self, range: R) -> LinkedList<T> {
+        let (start, end) = self.resolve_range(range);
+        let mut split = LinkedList::new();
+        let mut cur_node = self.link_at(start);
+        for _ in start..end {
+            let link = cur_node.unwrap();
+            cur_node = link.borrow().next_node.clone();
+            self.unlink(&link);
+            split.link_back(link);
+        }
+        split
+    }
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+    pub fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.length >= capacity)
+    }
+    pub fn heap_size(&self) -> usize {
+        self.heap_size_with(|_| 0)
+    }
+    pub fn heap_size_with<F>(&self, mut value_heap_size: F) -> usize
+    where
+        F: FnMut(&T) -> usize,
+    {
+        let node_size = 2 * mem::size_of::<usize>() + mem::size_of::<RefCell<Node<T>>>();
+        let mut size = (self.length + self.pool.len()) * node_size
+            + self.pool.capacity() * mem::size_of::<Rc<RefCell<Node<T>>>>();
+        if let Some(index) = &self.index {
+            size += index.heap_size();
+        }
+        let mut cur_node = self.head.clone();
+        for _ in 0..self.length {
+            let link = cur_node.unwrap();
+            size += value_heap_size(&link.borrow().value);
+            cur_node = link.borrow().next_node.clone();
+        }
+        size
+    }
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+    pub fn freeze(self) -> FrozenList<T> {
+        FrozenList {
+            values: self.into_iter().collect(),
+        }
+    }
+    pub fn recycle_capacity(&mut self, capacity: usize) {
+        self.recycle_capacity = capacity;
+        self.pool.truncate(capacity);
+    }
+    fn alloc(&mut self, value: T) -> Rc<RefCell<Node<T>>> {
+        if let Some(link) = self.pool.pop() {
+            link.borrow_mut().value = value;
+            return link;
+        }
         let mut new_node = Node::<T>::new();
         new_node.value = value;
-        let link = Rc::new(RefCell::new(new_node));
+        self.allocations += 1;
+        Rc::new(RefCell::new(new_node))
+    }
+    fn release(&mut self, link: Rc<RefCell<Node<T>>>) -> T {
+        let value = mem::take(&mut link.borrow_mut().value);
+        if Rc::strong_count(&link) == 1 && self.pool.len() < self.recycle_capacity {
+            self.pool.push(link);
+        }
+        value
+    }
+    fn select_node<F>(&self, mut replaces: F) -> Option<NodeRef<T>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut best = self.head.clone()?;
+        let mut cur_node = best.borrow().next_node.clone();
+        for _ in 1..self.length {
+            let link = cur_node.unwrap();
+            if replaces(&link.borrow().value, &best.borrow().value) {
+                best = link.clone();
+            }
+            cur_node = link.borrow().next_node.clone();
+        }
+        Some(NodeRef { link: best })
+    }
+    fn make_room(&mut self) -> bool {
+        if let Some(capacity) = self.capacity {
+            if capacity == 0 {
+                return false;
+            }
+            if self.length >= capacity {
+                match self.overflow {
+                    OverflowPolicy::Reject => return false,
+                    OverflowPolicy::EvictHead => {
+                        self.pop_front();
+                    }
+                }
+            }
+        }
+        true
+    }
+    fn link_back(&mut self, link: Rc<RefCell<Node<T>>>) {
+        self.link_ring(&link);
+        self.index_insert(self.length - 1, &link);
+    }
+    fn link_front(&mut self, link: Rc<RefCell<Node<T>>>) {
+        self.link_ring(&link);
+        self.tail = link.borrow().prev_node.clone();
+        self.head = Some(link.clone());
+        self.index_insert(0, &link);
+    }
+    fn link_before(&mut self, index: usize, link: Rc<RefCell<Node<T>>>) {
+        if index == 0 {
+            return self.link_front(link);
+        }
+        if index == self.length {
+            return self.link_back(link);
+        }
+        let next = self.link_at(index).unwrap();
+        let prev = next.borrow().prev_node.clone().unwrap();
+        Self::assert_unguarded(&prev);
+        Self::assert_unguarded(&next);
+        {
+            let mut node_ref = link.borrow_mut();
+            node_ref.prev_node = Some(prev.clone());
+            node_ref.next_node = Some(next.clone());
+            node_ref.owner = self.id;
+        }
+        prev.borrow_mut().next_node = Some(link.clone());
+        next.borrow_mut().prev_node = Some(link.clone());
+        self.length += 1;
+        self.index_insert(index, &link);
+    }
+    fn index_insert(&mut self, position: usize, link: &Rc<RefCell<Node<T>>>) {
+        if let Some(index) = &mut self.index {
+            link.borrow_mut().slot = index.insert(position, link.clone());
+        }
+    }
+    fn link_ring(&mut self, link: &Rc<RefCell<Node<T>>>) {
+        if let (Some(head), Some(tail)) = (&self.head, &self.tail) {
+            Self::assert_unguarded(head);
+            Self::assert_unguarded(tail);
+        }
         let mut node_ref = link.borrow_mut();
+        node_ref.owner = self.id;
         if self.head.is_none() {
             node_ref.next_node = Some(link.clone());
             node_ref.prev_node = Some(link.clone());
@@ -77,6 +570,133 @@ impl<T: Clone + Default> LinkedList<T> {
             self.head = Some(head);
             self.tail = Some(link.clone());
         }
+        self.length += 1;
+    }
+    fn eq_slice(&self, other: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.length == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(node, value)| node.value == *value)
+    }
+    fn link_at(&self, index: usize) -> Option<Rc<RefCell<Node<T>>>> {
+        if index >= self.length {
+            return None;
+        }
+        if let Some(position_index) = &self.index {
+            return position_index.get(index).cloned();
+        }
+        let mut cur_node;
+        if index < self.length / 2 {
+            cur_node = self.head.clone().unwrap();
+            for _ in 0..index {
+                let next = cur_node.borrow().next_node.clone().unwrap();
+                cur_node = next;
+            }
+        } else {
+            cur_node = self.tail.clone().unwrap();
+            for _ in index + 1..self.length {
+                let prev = cur_node.borrow().prev_node.clone().unwrap();
+                cur_node = prev;
+            }
+        }
+        Some(cur_node)
+    }
+    fn resolve_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => match start.checked_add(1) {
+                Some(start) => start,
+                None => panic!("`range` is out of bounds!"),
+            },
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => match end.checked_add(1) {
+                Some(end) => end,
+                None => panic!("`range` is out of bounds!"),
+            },
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.length,
+        };
+        if start > end || end > self.length {
+            panic!("`range` is out of bounds!");
+        }
+        (start, end)
+    }
+    fn assert_unguarded(link: &Rc<RefCell<Node<T>>>) {
+        if link.try_borrow_mut().is_err() {
+            panic!("`Node` is held by a `NodeGuard`!");
+        }
+    }
+    fn assert_owned(&self, link: &Rc<RefCell<Node<T>>>) {
+        let node_ref = match link.try_borrow() {
+            Ok(node_ref) => node_ref,
+            Err(_) => panic!("`Node` is held by a `NodeGuard`!"),
+        };
+        if node_ref.next_node.is_none() {
+            panic!("`Node` is not linked!");
+        }
+        if node_ref.owner != self.id {
+            panic!("`Node` does not belong to this `LinkedList`!");
+        }
+    }
+    fn unlink(&mut self, link: &Rc<RefCell<Node<T>>>) {
+        self.assert_owned(link);
+        let (prev, next) = {
+            let node_ref = link.borrow();
+            (
+                node_ref.prev_node.clone().unwrap(),
+                node_ref.next_node.clone().unwrap(),
+            )
+        };
+        Self::assert_unguarded(&prev);
+        Self::assert_unguarded(&next);
+        {
+            let mut node_ref = link.borrow_mut();
+            if let Some(index) = &mut self.index {
+                let indexed = index
+                    .value(node_ref.slot)
+                    .is_some_and(|indexed| Rc::ptr_eq(indexed, link));
+                if !indexed {
+                    panic!("`Node` is missing from the position index!");
+                }
+                index.remove(node_ref.slot);
+            }
+            node_ref.prev_node = None;
+            node_ref.next_node = None;
+        }
+        if Rc::ptr_eq(&next, link) {
+            self.head = None;
+            self.tail = None;
+        } else {
+            prev.borrow_mut().next_node = Some(next.clone());
+            next.borrow_mut().prev_node = Some(prev.clone());
+            if self
+                .head
+                .as_ref()
+                .is_some_and(|head| Rc::ptr_eq(head, link))
+            {
+                self.head = Some(next);
+            }
+            if self
+                .tail
+                .as_ref()
+                .is_some_and(|tail| Rc::ptr_eq(tail, link))
+            {
+                self.tail = Some(prev);
+            }
+        }
+        self.length -= 1;
+    }
+    pub fn len(&self) -> usize {
+        self.length
+    }
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
     }
     pub fn head(&self) -> Node<T> {
         if self.head.is_none() {
@@ -100,60 +720,754 @@ impl<T: Clone + Default> LinkedList<T> {
         let next_node = node.next_node.clone();
         let next_unwrap = next_node.unwrap();
         let cur_node = next_unwrap.borrow().prev_node.clone().unwrap();
-        if Rc::ptr_eq(&self.tail.clone().unwrap(), &cur_node) {
-            true
-        } else {
-            false
-        }
+        Rc::ptr_eq(&self.tail.clone().unwrap(), &cur_node)
     }
     pub fn is_head(&self, node: &Node<T>) -> bool {
         let next_node = node.next_node.clone();
         let next_unwrap = next_node.unwrap();
         let cur_node = next_unwrap.borrow().prev_node.clone().unwrap();
-        if Rc::ptr_eq(&self.head.clone().unwrap(), &cur_node) {
-            true
+        Rc::ptr_eq(&self.head.clone().unwrap(), &cur_node)
+    }
+    pub fn iter(&self) -> LinkedListIter<T> {
+        LinkedListIter {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+            remaining: self.length,
+            started: false,
+        }
+    }
+    pub fn iter_range<R: RangeBounds<usize>>(&self, range: R) -> LinkedListIter<T> {
+        let (start, end) = self.resolve_range(range);
+        if start == end {
+            return LinkedListIter {
+                front: None,
+                back: None,
+                remaining: 0,
+                started: false,
+            };
+        }
+        let front = self.link_at(start).unwrap();
+        let span = end - 1 - start;
+        let back = if self.index.is_none() && span < (end - 1).min(self.length - end) {
+            let mut cur_node = front.clone();
+            for _ in 0..span {
+                let next = cur_node.borrow().next_node.clone().unwrap();
+                cur_node = next;
+            }
+            cur_node
         } else {
-            false
+            self.link_at(end - 1).unwrap()
+        };
+        LinkedListIter {
+            front: Some(front),
+            back: Some(back),
+            remaining: end - start,
+            started: false,
         }
     }
-    pub fn iter(&self) -> LinkedListIter<T> {
+    pub fn iter_from(&self, node: &NodeRef<T>) -> LinkedListIter<T> {
+        self.assert_owned(&node.link);
         LinkedListIter {
-            head: self.head.clone(),
-            cur_node: self.head.clone(),
+            front: Some(node.link.clone()),
+            back: node.link.borrow().prev_node.clone(),
+            remaining: self.length,
+            started: false,
+        }
+    }
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let (head, tail) = match (self.head.clone(), self.tail.clone()) {
+            (None, None) if self.length == 0 => return Ok(()),
+            (None, None) => {
+                return Err(ValidationError::LengthMismatch {
+                    expected: self.length,
+                    found: 0,
+                })
+            }
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return Err(ValidationError::HeadTailMismatch),
+        };
+        if let Some(position_index) = &self.index {
+            if position_index.len() != self.length {
+                return Err(ValidationError::IndexMismatch { index: self.length });
+            }
+        }
+        let mut cur_node = head.clone();
+        let mut index = 0;
+        loop {
+            if let Some(position_index) = &self.index {
+                let slot = cur_node.borrow().slot;
+                let indexed = position_index
+                    .value(slot)
+                    .is_some_and(|indexed| Rc::ptr_eq(indexed, &cur_node));
+                if !indexed || position_index.rank(slot) != Some(index) {
+                    return Err(ValidationError::IndexMismatch { index });
+                }
+            }
+            let next = cur_node.borrow().next_node.clone();
+            let next = next.ok_or(ValidationError::MissingLink {
+                index,
+                direction: Direction::Next,
+            })?;
+            let prev = cur_node.borrow().prev_node.clone();
+            let prev = prev.ok_or(ValidationError::MissingLink {
+                index,
+                direction: Direction::Prev,
+            })?;
+            let next_back = next.borrow().prev_node.clone();
+            if !next_back.is_some_and(|link| Rc::ptr_eq(&link, &cur_node)) {
+                return Err(ValidationError::BrokenLink {
+                    index,
+                    direction: Direction::Next,
+                });
+            }
+            let prev_forward = prev.borrow().next_node.clone();
+            if !prev_forward.is_some_and(|link| Rc::ptr_eq(&link, &cur_node)) {
+                return Err(ValidationError::BrokenLink {
+                    index,
+                    direction: Direction::Prev,
+                });
+            }
+            index += 1;
+            if Rc::ptr_eq(&next, &head) {
+                if !Rc::ptr_eq(&cur_node, &tail) {
+                    return Err(ValidationError::TailMismatch { index: index - 1 });
+                }
+                break;
+            }
+            if index >= self.length {
+                return Err(ValidationError::RingTooLong {
+                    expected: self.length,
+                });
+            }
+            cur_node = next;
+        }
+        if index != self.length {
+            return Err(ValidationError::LengthMismatch {
+                expected: self.length,
+                found: index,
+            });
+        }
+        Ok(())
+    }
+    pub fn debug_assert_valid(&self) {
+        if cfg!(debug_assertions) {
+            if let Err(error) = self.validate() {
+                panic!("`LinkedList` is invalid: {}", error);
+            }
+        }
+    }
+}
+
+impl<T: Clone + Default + fmt::Display> LinkedList<T> {
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph LinkedList {\n    node [shape=box];\n");
+        let mut seen = HashSet::new();
+        let mut cur_node = self.head.clone();
+        while let Some(link) = cur_node {
+            if !seen.insert(Rc::as_ptr(&link)) {
+                break;
+            }
+            let node = link.borrow();
+            let mut label = node
+                .value
+                .to_string()
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+            if self
+                .head
+                .as_ref()
+                .is_some_and(|head| Rc::ptr_eq(head, &link))
+            {
+                label.push_str("\\n(head)");
+            }
+            if self
+                .tail
+                .as_ref()
+                .is_some_and(|tail| Rc::ptr_eq(tail, &link))
+            {
+                label.push_str("\\n(tail)");
+            }
+            writeln!(
+                dot,
+                "    \"{:p}\" [label=\"{}\"];",
+                Rc::as_ptr(&link),
+                label
+            )
+            .unwrap();
+            if let Some(next) = &node.next_node {
+                writeln!(
+                    dot,
+                    "    \"{:p}\" -> \"{:p}\" [label=\"next\"];",
+                    Rc::as_ptr(&link),
+                    Rc::as_ptr(next)
+                )
+                .unwrap();
+            }
+            if let Some(prev) = &node.prev_node {
+                writeln!(
+                    dot,
+                    "    \"{:p}\" -> \"{:p}\" [label=\"prev\", style=dashed];",
+                    Rc::as_ptr(&link),
+                    Rc::as_ptr(prev)
+                )
+                .unwrap();
+            }
+            cur_node = node.next_node.clone();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<A: Clone + Default, B: Clone + Default> LinkedList<(A, B)> {
+    pub fn unzip(mut self) -> (LinkedList<A>, LinkedList<B>) {
+        let mut left = LinkedList::new();
+        let mut right = LinkedList::new();
+        while let Some((a, b)) = self.pop_front() {
+            left.add(a);
+            right.add(b);
+        }
+        (left, right)
+    }
+}
+
+impl<T: Clone + Default> FrozenList<T> {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.values.iter()
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+    pub fn thaw(&self) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        for value in self.values.iter() {
+            list.add(value.clone());
+        }
+        list
+    }
+}
+
+impl<T: Clone + Default> Default for LinkedList<T> {
+    fn default() -> Self {
+        LinkedList::new()
+    }
+}
+
+impl<T: Clone + Default> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.index = None;
+        let mut cur_node = self.head.take();
+        while let Some(link) = cur_node {
+            cur_node = match link.try_borrow_mut() {
+                Ok(mut node_ref) => {
+                    node_ref.prev_node = None;
+                    node_ref.next_node.take()
+                }
+                Err(_) => None,
+            };
+        }
+        let mut cur_node = self.tail.take();
+        while let Some(link) = cur_node {
+            cur_node = match link.try_borrow_mut() {
+                Ok(mut node_ref) => {
+                    node_ref.next_node = None;
+                    node_ref.prev_node.take()
+                }
+                Err(_) => None,
+            };
+        }
+        self.length = 0;
+    }
+}
+
+impl<T: Clone + Default + fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|node| node.value))
+            .finish()
+    }
+}
+
+impl<T: Clone + Default + PartialEq> PartialEq<Vec<T>> for LinkedList<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<T: Clone + Default + PartialEq> PartialEq<&[T]> for LinkedList<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<T: Clone + Default + PartialEq, const N: usize> PartialEq<[T; N]> for LinkedList<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::HeadTailMismatch => write!(f, "only one of `head` and `tail` is set"),
+            ValidationError::MissingLink { index, direction } => {
+                write!(f, "node {} has no {:?} link", index, direction)
+            }
+            ValidationError::BrokenLink { index, direction } => write!(
+                f,
+                "node {} is not linked back from its {:?} node",
+                index, direction
+            ),
+            ValidationError::TailMismatch { index } => {
+                write!(f, "node {} closes the ring but is not the tail", index)
+            }
+            ValidationError::IndexMismatch { index } => {
+                write!(
+                    f,
+                    "position index disagrees with the ring at node {}",
+                    index
+                )
+            }
+            ValidationError::LengthMismatch { expected, found } => {
+                write!(f, "expected {} nodes but found {}", expected, found)
+            }
+            ValidationError::RingTooLong { expected } => {
+                write!(f, "ring continues past the expected {} nodes", expected)
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`LinkedList` is full")
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityError<T> {}
+
+impl<T: Clone + Default> LinkedListIter<T> {
+    pub fn peek_next(&self) -> Option<Node<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.front.as_ref().map(|link| link.borrow().clone())
+    }
+    pub fn peek_prev(&self) -> Option<Node<T>> {
+        if !self.started {
+            return None;
         }
+        let front = self.front.as_ref()?;
+        let prev = front.borrow().prev_node.clone()?;
+        let prev_t = prev.borrow().clone();
+        Some(prev_t)
     }
 }
 
 impl<T: Clone + Default> Iterator for LinkedListIter<T> {
     type Item = Node<T>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.cur_node.is_none() {
-            None
-        } else {
-            let cur_t = self.cur_node.clone().unwrap().borrow().clone();
-            let next = self.cur_node.clone().unwrap().borrow().clone().next_node;
-            if Rc::ptr_eq(&self.head.clone().unwrap(), &next.clone().unwrap()) {
-                self.cur_node = None;
-            } else {
-                self.cur_node = next;
-            }
-            Some(cur_t)
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let cur_t = self.front.take()?.borrow().clone();
+        self.front = cur_t.next_node.clone();
+        self.started = true;
+        Some(cur_t)
+    }
+    fn nth(&mut self, n: usize) -> Option<Node<T>> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            self.front = None;
+            self.back = None;
+            return None;
         }
+        for _ in 0..n {
+            let next = self.front.take()?.borrow().next_node.clone();
+            self.front = next;
+        }
+        self.remaining -= n;
+        self.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+    fn count(self) -> usize {
+        self.remaining
+    }
+    fn last(mut self) -> Option<Node<T>> {
+        self.next_back()
     }
 }
 
 impl<T: Clone + Default> DoubleEndedIterator for LinkedListIter<T> {
     fn next_back(&mut self) -> Option<Node<T>> {
-        if self.cur_node.is_none() {
-            None
-        } else {
-            let next_back = self.cur_node.clone().unwrap().borrow().prev_node.clone();
-            if Rc::ptr_eq(&self.head.clone().unwrap(), &next_back.clone().unwrap()) {
-                self.cur_node = None;
-            } else {
-                self.cur_node = next_back.clone();
-            }
-            Some(next_back.clone().unwrap().borrow().clone())
+        if self.remaining == 0 {
+            return None;
         }
+        self.remaining -= 1;
+        let cur_t = self.back.take()?.borrow().clone();
+        self.back = cur_t.prev_node.clone();
+        Some(cur_t)
+    }
+    fn nth_back(&mut self, n: usize) -> Option<Node<T>> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            self.front = None;
+            self.back = None;
+            return None;
+        }
+        for _ in 0..n {
+            let prev = self.back.take()?.borrow().prev_node.clone();
+            self.back = prev;
+        }
+        self.remaining -= n;
+        self.next_back()
+    }
+}
+
+impl<T: Clone + Default> ExactSizeIterator for LinkedListIter<T> {}
+
+impl<T: Clone + Default> FusedIterator for LinkedListIter<T> {}
+
+impl<'a, T: Clone + Default> IntoIterator for &'a LinkedList<T> {
+    type Item = T;
+    type IntoIter = LinkedListValues<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedListValues {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+            remaining: self.length,
+            list: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone + Default> Iterator for LinkedListValues<'_, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let link = self.front.take()?;
+        let node = link.borrow();
+        self.front = node.next_node.clone();
+        Some(node.value.clone())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone + Default> DoubleEndedIterator for LinkedListValues<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let link = self.back.take()?;
+        let node = link.borrow();
+        self.back = node.prev_node.clone();
+        Some(node.value.clone())
+    }
+}
+
+impl<T: Clone + Default> ExactSizeIterator for LinkedListValues<'_, T> {}
+
+impl<T: Clone + Default> FusedIterator for LinkedListValues<'_, T> {}
+
+impl<'a, T: Clone + Default> IntoIterator for &'a mut LinkedList<T> {
+    type Item = NodeRef<T>;
+    type IntoIter = LinkedListRefs<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedListRefs {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+            remaining: self.length,
+            list: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone + Default> Iterator for LinkedListRefs<'_, T> {
+    type Item = NodeRef<T>;
+    fn next(&mut self) -> Option<NodeRef<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let link = self.front.take()?;
+        self.front = link.borrow().next_node.clone();
+        Some(NodeRef { link })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone + Default> DoubleEndedIterator for LinkedListRefs<'_, T> {
+    fn next_back(&mut self) -> Option<NodeRef<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let link = self.back.take()?;
+        self.back = link.borrow().prev_node.clone();
+        Some(NodeRef { link })
+    }
+}
+
+impl<T: Clone + Default> ExactSizeIterator for LinkedListRefs<'_, T> {}
+
+impl<T: Clone + Default> FusedIterator for LinkedListRefs<'_, T> {}
+
+impl<T: Clone + Default> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = LinkedListIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedListIntoIter { list: self }
+    }
+}
+
+impl<T: Clone + Default> Iterator for LinkedListIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T: Clone + Default> DoubleEndedIterator for LinkedListIntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T: Clone + Default> ExactSizeIterator for LinkedListIntoIter<T> {}
+
+impl<T: Clone + Default> FusedIterator for LinkedListIntoIter<T> {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_of(values: &[i32]) -> LinkedList<i32> {
+        let mut list = LinkedList::new();
+        for value in values {
+            list.add(*value);
+        }
+        list
+    }
+
+    fn nth_link(list: &LinkedList<i32>, index: usize) -> Rc<RefCell<Node<i32>>> {
+        let mut link = list.head.clone().unwrap();
+        for _ in 0..index {
+            let next = link.borrow().next_node.clone().unwrap();
+            link = next;
+        }
+        link
+    }
+
+    #[test]
+    fn valid_lists_pass() {
+        assert_eq!(LinkedList::<i32>::new().validate(), Ok(()));
+        assert_eq!(list_of(&[1]).validate(), Ok(()));
+        assert_eq!(list_of(&[1, 2, 3]).validate(), Ok(()));
+    }
+
+    #[test]
+    fn detects_head_tail_mismatch() {
+        let mut list = list_of(&[1, 2]);
+        list.tail = None;
+        assert_eq!(list.validate(), Err(ValidationError::HeadTailMismatch));
+    }
+
+    #[test]
+    fn detects_missing_link() {
+        let list = list_of(&[1, 2, 3]);
+        nth_link(&list, 0).borrow_mut().next_node = None;
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::MissingLink {
+                index: 0,
+                direction: Direction::Next,
+            })
+        );
+    }
+
+    #[test]
+    fn detects_broken_link() {
+        let list = list_of(&[1, 2, 3]);
+        nth_link(&list, 1).borrow_mut().prev_node = Some(nth_link(&list, 2));
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::BrokenLink {
+                index: 0,
+                direction: Direction::Next,
+            })
+        );
+    }
+
+    #[test]
+    fn detects_tail_mismatch() {
+        let mut list = list_of(&[1, 2, 3]);
+        list.tail = Some(nth_link(&list, 1));
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::TailMismatch { index: 2 })
+        );
+    }
+
+    #[test]
+    fn detects_ring_too_long() {
+        let mut list = list_of(&[1, 2, 3]);
+        list.length = 2;
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::RingTooLong { expected: 2 })
+        );
+    }
+
+    #[test]
+    fn detects_length_mismatch() {
+        let mut list = list_of(&[1, 2, 3]);
+        list.length = 4;
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::LengthMismatch {
+                expected: 4,
+                found: 3,
+            })
+        );
+        let mut empty = LinkedList::<i32>::new();
+        empty.length = 1;
+        assert_eq!(
+            empty.validate(),
+            Err(ValidationError::LengthMismatch {
+                expected: 1,
+                found: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn to_dot_lists_nodes_and_edges() {
+        let mut list = LinkedList::new();
+        list.add(String::from("say \"hi\""));
+        list.add(String::from("back\\slash"));
+        let expected = format!(
+            r#"digraph LinkedList {{
+    node [shape=box];
+    "{h:p}" [label="say \"hi\"\n(head)"];
+    "{h:p}" -> "{t:p}" [label="next"];
+    "{h:p}" -> "{t:p}" [label="prev", style=dashed];
+    "{t:p}" [label="back\\slash\n(tail)"];
+    "{t:p}" -> "{h:p}" [label="next"];
+    "{t:p}" -> "{h:p}" [label="prev", style=dashed];
+}}
+"#,
+            h = Rc::as_ptr(list.head.as_ref().unwrap()),
+            t = Rc::as_ptr(list.tail.as_ref().unwrap()),
+        );
+        assert_eq!(list.to_dot(), expected);
+        assert_eq!(
+            LinkedList::<i32>::new().to_dot(),
+            "digraph LinkedList {\n    node [shape=box];\n}\n"
+        );
+    }
+
+    #[test]
+    fn released_nodes_are_reused() {
+        let mut list = LinkedList::new();
+        for value in 0..3 {
+            list.add(value);
+        }
+        list.pop_front();
+        assert!(list.pool.is_empty());
+        list.recycle_capacity(2);
+        let head = Rc::as_ptr(list.head.as_ref().unwrap());
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pool.len(), 1);
+        list.add(3);
+        assert!(list.pool.is_empty());
+        assert_eq!(Rc::as_ptr(list.tail.as_ref().unwrap()), head);
+        assert_eq!(list, [2, 3]);
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn held_handles_are_never_pooled() {
+        let mut list = LinkedList::new();
+        list.recycle_capacity(4);
+        let handle = list.add(1);
+        list.add(2);
+        assert_eq!(list.remove(&handle), 1);
+        assert!(list.pool.is_empty());
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pool.len(), 1);
+    }
+
+    #[test]
+    fn recycle_capacity_truncates_the_pool() {
+        let mut list = LinkedList::new();
+        list.recycle_capacity(4);
+        for value in 0..6 {
+            list.add(value);
+        }
+        while list.pop_back().is_some() {}
+        assert_eq!(list.pool.len(), 4);
+        list.recycle_capacity(1);
+        assert_eq!(list.pool.len(), 1);
+        list.recycle_capacity(0);
+        assert!(list.pool.is_empty());
+    }
+
+    #[test]
+    fn allocations_stay_flat_while_recycling() {
+        let mut list = LinkedList::new();
+        list.recycle_capacity(2);
+        for value in 0..3 {
+            list.add(value);
+        }
+        assert_eq!(list.allocations(), 3);
+        for value in 3..10 {
+            list.pop_front();
+            list.add(value);
+            assert_eq!(list.allocations(), 3);
+        }
+        assert_eq!(list, [7, 8, 9]);
+        list.recycle_capacity(0);
+        list.pop_front();
+        list.add(10);
+        assert_eq!(list.allocations(), 4);
+    }
+
+    #[test]
+    fn detects_index_mismatch() {
+        let mut list = list_of(&[1, 2, 3]);
+        list.enable_index();
+        assert_eq!(list.validate(), Ok(()));
+        let (first, second) = (nth_link(&list, 0), nth_link(&list, 1));
+        let slot = first.borrow().slot;
+        first.borrow_mut().slot = second.borrow().slot;
+        second.borrow_mut().slot = slot;
+        assert_eq!(
+            list.validate(),
+            Err(ValidationError::IndexMismatch { index: 0 })
+        );
     }
 }
@@ -0,0 +1,66 @@
+mod common;
+
+use common::values;
+use safe_linked_list_rust::{CapacityError, LinkedList, OverflowPolicy};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[test]
+fn reject_hands_the_value_back() {
+    let mut list = LinkedList::with_capacity_bounded(2, OverflowPolicy::Reject);
+    assert!(list.try_add(1).is_ok());
+    assert!(list.try_add(2).is_ok());
+    assert!(list.is_full());
+    assert_eq!(list.try_add(3).err(), Some(CapacityError { value: 3 }));
+    assert!(catch_unwind(AssertUnwindSafe(|| list.add(4))).is_err());
+    assert_eq!(values(&list), [1, 2]);
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn evict_head_drops_the_oldest_values() {
+    let mut list = LinkedList::with_capacity_bounded(3, OverflowPolicy::EvictHead);
+    for value in 1..=5 {
+        list.add(value);
+    }
+    assert_eq!(list.capacity(), Some(3));
+    assert_eq!(values(&list), [3, 4, 5]);
+    assert_eq!(list.validate(), Ok(()));
+    assert_eq!(list.pop_front(), Some(3));
+    assert!(!list.is_full());
+}
+
+#[test]
+fn zero_capacity_holds_nothing() {
+    for policy in &[OverflowPolicy::Reject, OverflowPolicy::EvictHead] {
+        let mut list = LinkedList::with_capacity_bounded(0, *policy);
+        assert!(list.is_full());
+        assert_eq!(list.try_add(1).err(), Some(CapacityError { value: 1 }));
+        assert!(list.is_empty());
+        assert_eq!(list.validate(), Ok(()));
+    }
+}
+
+#[test]
+fn unbounded_lists_never_fill() {
+    let mut list = LinkedList::new();
+    for value in 0..100 {
+        assert!(list.try_add(value).is_ok());
+    }
+    assert_eq!(list.capacity(), None);
+    assert!(!list.is_full());
+}
+
+#[test]
+fn insert_after_eviction_shifts_the_index() {
+    let mut list = LinkedList::with_capacity_bounded(3, OverflowPolicy::EvictHead);
+    for value in 1..=3 {
+        list.add(value);
+    }
+    list.insert(2, 9);
+    assert_eq!(list, [2, 9, 3]);
+    list.insert(0, 8);
+    assert_eq!(list, [8, 9, 3]);
+    list.insert(3, 7);
+    assert_eq!(list, [9, 3, 7]);
+    assert_eq!(list.validate(), Ok(()));
+}
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+use safe_linked_list_rust::LinkedList;
+
+pub fn values<T: Clone + Default>(list: &LinkedList<T>) -> Vec<T> {
+    list.iter().map(|node| node.value).collect()
+}
+
+pub fn list_of(values: &[i32]) -> LinkedList<i32> {
+    let mut list = LinkedList::new();
+    for value in values {
+        list.add(*value);
+    }
+    list
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "ffi")]
+
+use safe_linked_list_rust::ffi::*;
+use std::{os::raw::c_void, ptr, slice};
+
+extern "C" fn collect_i64(value: i64, user_data: *mut c_void) {
+    let out = unsafe { &mut *(user_data as *mut Vec<i64>) };
+    out.push(value);
+}
+
+extern "C" fn collect_bytes(data: *const u8, len: usize, user_data: *mut c_void) {
+    let out = unsafe { &mut *(user_data as *mut Vec<Vec<u8>>) };
+    out.push(unsafe { slice::from_raw_parts(data, len) }.to_vec());
+}
+
+#[test]
+fn i64_list_round_trip() {
+    unsafe {
+        let list = sll_i64_new();
+        for value in 1..=4 {
+            assert!(sll_i64_push(list, value));
+        }
+        assert_eq!(sll_i64_len(list), 4);
+        let mut seen = Vec::<i64>::new();
+        sll_i64_for_each(list, Some(collect_i64), &mut seen as *mut _ as *mut c_void);
+        assert_eq!(seen, [1, 2, 3, 4]);
+        let mut out = 0;
+        assert!(sll_i64_pop_front(list, &mut out));
+        assert_eq!(out, 1);
+        assert!(sll_i64_pop_back(list, &mut out));
+        assert_eq!(out, 4);
+        assert!(sll_i64_pop_back(list, ptr::null_mut()));
+        assert_eq!(sll_i64_len(list), 1);
+        sll_i64_free(list);
+    }
+}
+
+#[test]
+fn bytes_list_round_trip() {
+    unsafe {
+        let list = sll_bytes_new();
+        assert!(sll_bytes_push(list, b"ab".as_ptr(), 2));
+        assert!(sll_bytes_push(list, ptr::null(), 0));
+        assert!(!sll_bytes_push(list, ptr::null(), 3));
+        assert_eq!(sll_bytes_len(list), 2);
+        let mut seen = Vec::<Vec<u8>>::new();
+        sll_bytes_for_each(
+            list,
+            Some(collect_bytes),
+            &mut seen as *mut _ as *mut c_void,
+        );
+        assert_eq!(seen, [b"ab".to_vec(), Vec::new()]);
+        seen.clear();
+        assert!(sll_bytes_pop_front(
+            list,
+            Some(collect_bytes),
+            &mut seen as *mut _ as *mut c_void
+        ));
+        assert_eq!(seen, [b"ab".to_vec()]);
+        sll_bytes_free(list);
+    }
+}
+
+#[test]
+fn null_handles_and_callbacks_are_ignored() {
+    unsafe {
+        assert_eq!(sll_i64_len(ptr::null()), 0);
+        assert!(!sll_i64_push(ptr::null_mut(), 1));
+        assert!(!sll_i64_pop_front(ptr::null_mut(), ptr::null_mut()));
+        sll_i64_for_each(ptr::null(), Some(collect_i64), ptr::null_mut());
+        sll_i64_free(ptr::null_mut());
+        assert_eq!(sll_bytes_len(ptr::null()), 0);
+        assert!(!sll_bytes_pop_front(ptr::null_mut(), None, ptr::null_mut()));
+        sll_bytes_free(ptr::null_mut());
+
+        let list = sll_i64_new();
+        sll_i64_push(list, 7);
+        sll_i64_for_each(list, None, ptr::null_mut());
+        sll_i64_free(list);
+
+        let list = sll_bytes_new();
+        sll_bytes_push(list, b"x".as_ptr(), 1);
+        sll_bytes_for_each(list, None, ptr::null_mut());
+        assert!(sll_bytes_pop_front(list, None, ptr::null_mut()));
+        assert_eq!(sll_bytes_len(list), 0);
+        sll_bytes_free(list);
+    }
+}
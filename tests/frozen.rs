@@ -0,0 +1,39 @@
+mod common;
+
+use common::{list_of, values};
+use safe_linked_list_rust::FrozenList;
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn frozen_lists_are_send_and_sync() {
+    assert_send_sync::<FrozenList<i32>>();
+}
+
+#[test]
+fn freeze_and_thaw_round_trip() {
+    let frozen = list_of(&[1, 2, 3]).freeze();
+    assert_eq!(frozen.len(), 3);
+    assert_eq!(frozen.as_slice(), [1, 2, 3]);
+    assert_eq!(frozen.get(1), Some(&2));
+    assert_eq!(frozen.get(3), None);
+
+    let mut thawed = frozen.thaw();
+    assert_eq!(thawed.validate(), Ok(()));
+    thawed.add(4);
+    assert_eq!(values(&thawed), [1, 2, 3, 4]);
+    assert_eq!(frozen.as_slice(), [1, 2, 3]);
+    assert_eq!(values(&thawed.freeze().thaw()), [1, 2, 3, 4]);
+}
+
+#[test]
+fn frozen_lists_are_shared_across_threads() {
+    let frozen = list_of(&[1, 2, 3]).freeze();
+    let shared = frozen.clone();
+    let sum = thread::spawn(move || shared.iter().sum::<i32>())
+        .join()
+        .unwrap();
+    assert_eq!(sum, 6);
+    assert!(list_of(&[]).freeze().is_empty());
+}
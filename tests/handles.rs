@@ -0,0 +1,66 @@
+mod common;
+
+use common::{list_of, values};
+use safe_linked_list_rust::LinkedList;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[test]
+fn handles_move_and_remove_nodes() {
+    let mut list = LinkedList::new();
+    let handles: Vec<_> = (0..4).map(|value| list.add(value)).collect();
+    list.move_to_front(&handles[2]);
+    list.move_to_back(&handles[0]);
+    assert_eq!(values(&list), [2, 1, 3, 0]);
+    assert_eq!(list.remove(&handles[1]), 1);
+    assert_eq!(values(&list), [2, 3, 0]);
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn foreign_handle_is_rejected() {
+    let mut a = list_of(&[1, 2]);
+    let b = list_of(&[3, 4, 5]);
+    let foreign = b.tail_ref().unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| a.remove(&foreign))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| a.move_to_front(&foreign))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| a.move_to_back(&foreign))).is_err());
+    assert_eq!(a.validate(), Ok(()));
+    assert_eq!(b.validate(), Ok(()));
+    assert_eq!(values(&a), [1, 2]);
+    assert_eq!(values(&b), [3, 4, 5]);
+}
+
+#[test]
+fn removed_handle_is_rejected() {
+    let mut list = list_of(&[1, 2, 3]);
+    let node = list.head_ref().unwrap();
+    assert_eq!(list.remove(&node), 1);
+    assert!(catch_unwind(AssertUnwindSafe(|| list.remove(&node))).is_err());
+    assert_eq!(list.validate(), Ok(()));
+    assert_eq!(values(&list), [2, 3]);
+}
+
+#[test]
+fn handles_follow_nodes_between_lists() {
+    let mut list = LinkedList::new();
+    let handles: Vec<_> = (0..6).map(|value| list.add(value)).collect();
+    let (mut even, odd) = list.partition(|value| value % 2 == 0);
+    even.move_to_front(&handles[4]);
+    assert_eq!(values(&even), [4, 0, 2]);
+    assert!(catch_unwind(AssertUnwindSafe(|| even.remove(&handles[1]))).is_err());
+    assert_eq!(odd.validate(), Ok(()));
+}
+
+#[test]
+fn iter_from_rejects_stale_handles() {
+    let mut a = list_of(&[1, 2, 3]);
+    let b = list_of(&[4, 5]);
+    let removed = a.head_ref().unwrap();
+    a.remove(&removed);
+    assert!(catch_unwind(AssertUnwindSafe(|| a.iter_from(&removed))).is_err());
+    let foreign = b.head_ref().unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| a.iter_from(&foreign))).is_err());
+    let tail = a.tail_ref().unwrap();
+    let from_tail: Vec<_> = a.iter_from(&tail).map(|node| node.value).collect();
+    assert_eq!(from_tail, [3, 2]);
+}
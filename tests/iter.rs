@@ -0,0 +1,122 @@
+mod common;
+
+use common::list_of;
+use safe_linked_list_rust::{LinkedList, Node};
+
+fn collect<I: Iterator<Item = Node<i32>>>(iter: I) -> Vec<i32> {
+    iter.map(|node| node.value).collect()
+}
+
+#[test]
+fn next_and_next_back_meet_in_the_middle() {
+    let list = list_of(&[0, 1, 2, 3, 4]);
+    let mut iter = list.iter();
+    assert_eq!(iter.next().map(|node| node.value), Some(0));
+    assert_eq!(iter.next_back().map(|node| node.value), Some(4));
+    assert_eq!(iter.next().map(|node| node.value), Some(1));
+    assert_eq!(iter.next_back().map(|node| node.value), Some(3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back().map(|node| node.value), Some(2));
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn rev_after_partial_forward_iteration() {
+    let list = list_of(&[0, 1, 2, 3, 4]);
+    let mut iter = list.iter();
+    iter.next();
+    iter.next();
+    assert_eq!(collect(iter.rev()), [4, 3, 2]);
+}
+
+#[test]
+fn rfind_searches_from_the_back() {
+    let list = list_of(&[1, 2, 3, 4]);
+    let mut iter = list.iter();
+    let found = iter.rfind(|node| node.value % 2 == 1);
+    assert_eq!(found.map(|node| node.value), Some(3));
+    assert_eq!(collect(iter), [1, 2]);
+}
+
+#[test]
+fn single_element_is_yielded_once() {
+    let list = list_of(&[7]);
+    let mut iter = list.iter();
+    assert_eq!(iter.next_back().map(|node| node.value), Some(7));
+    assert!(iter.next().is_none());
+    assert_eq!(collect(list.iter().rev()), [7]);
+    assert_eq!(collect(list.iter()), [7]);
+}
+
+#[test]
+fn iter_from_reversed_wraps_around() {
+    let mut list = LinkedList::new();
+    let handles: Vec<_> = (0..4).map(|value| list.add(value)).collect();
+    assert_eq!(collect(list.iter_from(&handles[2])), [2, 3, 0, 1]);
+    assert_eq!(collect(list.iter_from(&handles[2]).rev()), [1, 0, 3, 2]);
+}
+
+#[test]
+fn iter_range_reversed_matches_slices() {
+    let values: Vec<i32> = (0..10).collect();
+    let mut list = list_of(&values);
+    check_ranges(&list, &values);
+    list.enable_index();
+    check_ranges(&list, &values);
+}
+
+fn check_ranges(list: &LinkedList<i32>, values: &[i32]) {
+    for start in 0..=values.len() {
+        for end in start..=values.len() {
+            let forward = collect(list.iter_range(start..end));
+            assert_eq!(forward, &values[start..end]);
+            let mut backward = collect(list.iter_range(start..end).rev());
+            backward.reverse();
+            assert_eq!(backward, &values[start..end]);
+        }
+    }
+}
+
+#[test]
+fn peeks_follow_the_front_cursor() {
+    let list = list_of(&[0, 1, 2]);
+    let mut iter = list.iter();
+    assert!(iter.peek_prev().is_none());
+    assert_eq!(iter.peek_next().map(|node| node.value), Some(0));
+    iter.next();
+    assert_eq!(iter.peek_prev().map(|node| node.value), Some(0));
+    assert_eq!(iter.peek_next().map(|node| node.value), Some(1));
+    iter.next();
+    iter.next();
+    assert_eq!(iter.peek_prev().map(|node| node.value), Some(2));
+    assert!(iter.peek_next().is_none());
+}
+
+#[test]
+fn nth_skips_without_losing_the_cursors() {
+    let list = list_of(&[0, 1, 2, 3, 4, 5]);
+    let mut iter = list.iter();
+    assert_eq!(iter.nth(1).map(|node| node.value), Some(1));
+    assert_eq!(iter.nth_back(1).map(|node| node.value), Some(4));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.peek_prev().map(|node| node.value), Some(1));
+    assert_eq!(collect(iter), [2, 3]);
+}
+
+#[test]
+fn overshooting_nth_exhausts_the_peeks() {
+    let list = list_of(&[0, 1, 2]);
+    let mut iter = list.iter();
+    iter.next();
+    assert!(iter.nth(10).is_none());
+    assert!(iter.peek_prev().is_none());
+    assert!(iter.peek_next().is_none());
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+
+    let mut iter = list.iter();
+    assert!(iter.nth_back(3).is_none());
+    assert!(iter.peek_prev().is_none());
+    assert_eq!(iter.len(), 0);
+}
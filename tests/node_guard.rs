@@ -0,0 +1,42 @@
+mod common;
+
+use common::{list_of, values};
+use safe_linked_list_rust::LinkedList;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[test]
+fn guard_mutates_in_place() {
+    let list = list_of(&[1, 2, 3]);
+    let tail = list.tail_ref().unwrap();
+    *tail.borrow_mut().unwrap() += 40;
+    assert_eq!(values(&list), [1, 2, 43]);
+}
+
+#[test]
+fn second_guard_is_an_error() {
+    let list = list_of(&[1, 2]);
+    let head = list.head_ref().unwrap();
+    let guard = head.borrow_mut().unwrap();
+    assert!(head.borrow_mut().is_err());
+    drop(guard);
+    assert!(head.borrow_mut().is_ok());
+}
+
+#[test]
+fn remove_while_guarded_is_refused() {
+    let mut list = LinkedList::new();
+    let first = list.add(1);
+    let second = list.add(2);
+    let mut guard = first.borrow_mut().unwrap();
+    *guard = 100;
+    assert!(catch_unwind(AssertUnwindSafe(|| list.remove(&first))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| list.remove(&second))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| list.move_to_front(&second))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| list.add(3))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| first.set(5))).is_err());
+    drop(guard);
+    assert_eq!(list.validate(), Ok(()));
+    assert_eq!(values(&list), [100, 2]);
+    assert_eq!(list.remove(&first), 100);
+    assert_eq!(values(&list), [2]);
+}
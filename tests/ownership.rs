@@ -0,0 +1,58 @@
+mod common;
+
+use common::values;
+use safe_linked_list_rust::LinkedList;
+use std::rc::Rc;
+
+fn tracked_list(tracker: &Rc<()>, len: usize) -> LinkedList<Rc<()>> {
+    let mut list = LinkedList::new();
+    for _ in 0..len {
+        list.add(tracker.clone());
+    }
+    list
+}
+
+#[test]
+fn dropping_a_list_frees_its_nodes() {
+    let tracker = Rc::new(());
+    drop(tracked_list(&tracker, 5));
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn dropping_a_partial_into_iter_frees_its_nodes() {
+    let tracker = Rc::new(());
+    let mut iter = tracked_list(&tracker, 5).into_iter();
+    iter.next();
+    iter.next_back();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn iterating_mutably_yields_handles() {
+    let mut list = LinkedList::new();
+    for i in 1..5 {
+        list.add(i);
+    }
+    for node in &mut list {
+        *node.borrow_mut().unwrap() *= 10;
+    }
+    assert_eq!(values(&list), [10, 20, 30, 40]);
+    let handles: Vec<_> = (&mut list).into_iter().rev().collect();
+    assert_eq!(handles.len(), 4);
+    list.remove(&handles[0]);
+    assert_eq!(values(&list), [10, 20, 30]);
+}
+
+#[test]
+fn dropping_a_list_with_a_live_guard_frees_its_nodes() {
+    let tracker = Rc::new(());
+    let mut list = tracked_list(&tracker, 5);
+    let handles: Vec<_> = (&mut list).into_iter().collect();
+    let guard = handles[2].borrow_mut().unwrap();
+    drop(list);
+    drop(guard);
+    drop(handles);
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
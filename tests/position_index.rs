@@ -0,0 +1,98 @@
+mod common;
+
+use common::list_of;
+use safe_linked_list_rust::LinkedList;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+fn check_against_vec(indexed: bool) {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    let mut list = LinkedList::new();
+    let mut model: Vec<i32> = Vec::new();
+    if indexed {
+        list.enable_index();
+    }
+    for step in 0..5000 {
+        match rng.below(6) {
+            0 | 1 => {
+                let index = rng.below(model.len() + 1);
+                list.insert(index, step);
+                model.insert(index, step);
+            }
+            2 if !model.is_empty() => {
+                let index = rng.below(model.len());
+                assert_eq!(list.remove_at(index), model.remove(index));
+            }
+            3 if !model.is_empty() => {
+                let index = rng.below(model.len());
+                assert_eq!(list.swap_remove(index), model.swap_remove(index));
+            }
+            4 if !model.is_empty() => {
+                let index = rng.below(model.len());
+                let node = list.insert(index, -step);
+                model.insert(index, -step);
+                list.move_to_front(&node);
+                let value = model.remove(index);
+                model.insert(0, value);
+            }
+            _ if !model.is_empty() => {
+                let index = rng.below(model.len());
+                assert_eq!(list.get(index).unwrap().value, model[index]);
+            }
+            _ => {}
+        }
+        if step % 250 == 0 {
+            assert_eq!(list.validate(), Ok(()));
+            assert_eq!(list, model);
+        }
+    }
+    assert_eq!(list.validate(), Ok(()));
+    assert_eq!(list, model);
+}
+
+#[test]
+fn positional_operations_match_vec() {
+    check_against_vec(false);
+}
+
+#[test]
+fn indexed_positional_operations_match_vec() {
+    check_against_vec(true);
+}
+
+#[test]
+fn index_survives_splits_and_rebuilds() {
+    let mut list = LinkedList::new();
+    for value in 0..20 {
+        list.add(value);
+    }
+    list.enable_index();
+    let mut middle = list.split_range(5..15);
+    assert_eq!(list.validate(), Ok(()));
+    assert_eq!(list.get(5).unwrap().value, 15);
+    middle.enable_index();
+    assert_eq!(middle.remove_at(0), 5);
+    assert_eq!(middle.validate(), Ok(()));
+}
+
+#[test]
+fn foreign_handle_leaves_index_intact() {
+    let mut a = list_of(&[0, 1, 2]);
+    let mut b = list_of(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    a.enable_index();
+    b.enable_index();
+    let foreign = b.tail_ref().unwrap();
+    assert!(catch_unwind(AssertUnwindSafe(|| a.remove(&foreign))).is_err());
+    assert_eq!(a.validate(), Ok(()));
+    assert_eq!(b.validate(), Ok(()));
+}
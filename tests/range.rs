@@ -0,0 +1,32 @@
+mod common;
+
+use common::list_of;
+use safe_linked_list_rust::Node;
+use std::{
+    ops::Bound,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+fn range_values<I: Iterator<Item = Node<i32>>>(iter: I) -> Vec<i32> {
+    iter.map(|node| node.value).collect()
+}
+
+#[test]
+fn iter_range_yields_the_requested_slice() {
+    let list = list_of(&[0, 1, 2, 3, 4, 5]);
+    assert_eq!(range_values(list.iter_range(1..3)), [1, 2]);
+    assert_eq!(range_values(list.iter_range(4..)), [4, 5]);
+    assert_eq!(range_values(list.iter_range(..=1)), [0, 1]);
+    assert_eq!(range_values(list.iter_range(..)), [0, 1, 2, 3, 4, 5]);
+    assert!(range_values(list.iter_range(3..3)).is_empty());
+    assert!(range_values(list.iter_range(6..)).is_empty());
+}
+
+#[test]
+fn out_of_bounds_ranges_panic() {
+    let list = list_of(&[0, 1, 2]);
+    assert!(catch_unwind(AssertUnwindSafe(|| list.iter_range(2..5))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| list.iter_range(..=usize::MAX))).is_err());
+    let excluded = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+    assert!(catch_unwind(AssertUnwindSafe(|| list.iter_range(excluded))).is_err());
+}